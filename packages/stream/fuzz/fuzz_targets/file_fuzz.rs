@@ -3,7 +3,7 @@
 extern crate stream;
 
 use libfuzzer_sys::fuzz_target;
-use stream::file::FileStream;
+use stream::file::{FileStream, OpenType};
 use std::io::Write;
 use std::io::Read;
 use crate::stream::SeekStream;
@@ -11,7 +11,7 @@ use tempfile::{tempdir, tempfile};
 
 fuzz_target!(|data: &[u8]| {
     let temp_dir = tempdir().unwrap();
-    let mut stream = FileStream::new_write(temp_dir.path().join("fuzz_target")).expect("file open");
+    let mut stream = FileStream::open(temp_dir.path().join("fuzz_target"), OpenType::OpenAndCreate).expect("file open");
 
     stream.write_all(data).expect("all written");
     stream.seek(0);
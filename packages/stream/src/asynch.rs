@@ -0,0 +1,234 @@
+//! Async stream variants backed by tokio, mirroring the sync stream traits.
+use crate::Result;
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures::Stream;
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{
+    AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt, BufReader,
+    BufWriter,
+};
+
+/// Async counterpart of [`crate::SeekStream`].
+#[async_trait]
+pub trait AsyncSeekStream {
+    /// Seek to a position.
+    async fn seek(&mut self, to: u64) -> Result<u64>;
+    /// Get the current position.
+    async fn position(&mut self) -> Result<u64>;
+    /// Get the length of the stream.
+    async fn len(&mut self) -> Result<u64>;
+}
+
+/// Async counterpart of [`crate::ReadStream`].
+pub trait AsyncReadStream: AsyncRead + AsyncSeekStream + Unpin {}
+
+/// Async counterpart of [`crate::WriteStream`].
+pub trait AsyncWriteStream: AsyncWrite + AsyncSeekStream + Unpin {}
+
+/// Stream that wraps a tokio [`File`].
+pub struct TokioFileStream {
+    position: u64,
+    len: u64,
+    // `None` only ever appears transiently while a mode transition is in
+    // progress inside a single poll call.
+    mode: Option<TokioFileStreamMode>,
+}
+
+pub enum TokioFileStreamMode {
+    Read(BufReader<File>),
+    /// Mid-transition from `Read` to `Write`: the writer has been created
+    /// and a seek back to `position` has been started via
+    /// [`AsyncSeek::start_seek`] but may not have completed yet.
+    Switching(BufWriter<File>),
+    Write(BufWriter<File>),
+}
+
+impl TokioFileStream {
+    /// Open a file for async reading, creating it if it does not exist.
+    pub async fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(path)
+            .await?;
+        let len = file.metadata().await?.len();
+        Ok(Self {
+            position: 0,
+            len,
+            mode: Some(TokioFileStreamMode::Read(BufReader::new(file))),
+        })
+    }
+
+    /// Drain a byte stream into the file, writing each chunk as it arrives.
+    pub async fn write_from_stream<S>(&mut self, mut source: S) -> Result<u64>
+    where
+        S: Stream<Item = io::Result<Bytes>> + Unpin,
+    {
+        use futures::StreamExt;
+
+        let mut written = 0u64;
+        while let Some(chunk) = source.next().await {
+            let chunk = chunk?;
+            self.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        self.flush().await?;
+        Ok(written)
+    }
+
+    /// Pump the remaining contents of the file into `writer`.
+    pub async fn read_to_async_write<W>(&mut self, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        Ok(tokio::io::copy(self, writer).await?)
+    }
+
+    fn mode_mut(&mut self) -> &mut TokioFileStreamMode {
+        self.mode.as_mut().expect("mode is always populated")
+    }
+}
+
+#[async_trait]
+impl AsyncSeekStream for TokioFileStream {
+    async fn seek(&mut self, to: u64) -> Result<u64> {
+        let result = match self.mode_mut() {
+            TokioFileStreamMode::Read(reader) => reader.seek(io::SeekFrom::Start(to)).await,
+            TokioFileStreamMode::Switching(writer) | TokioFileStreamMode::Write(writer) => {
+                writer.seek(io::SeekFrom::Start(to)).await
+            }
+        }?;
+        self.position = to;
+        Ok(result)
+    }
+
+    async fn position(&mut self) -> Result<u64> {
+        Ok(self.position)
+    }
+
+    async fn len(&mut self) -> Result<u64> {
+        Ok(self.len)
+    }
+}
+
+impl AsyncRead for TokioFileStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before = buf.filled().len();
+        let result = match self.mode_mut() {
+            TokioFileStreamMode::Read(reader) => Pin::new(reader).poll_read(cx, buf),
+            TokioFileStreamMode::Switching(_) | TokioFileStreamMode::Write(_) => {
+                return Poll::Ready(Err(io::ErrorKind::Unsupported.into()))
+            }
+        };
+        if let Poll::Ready(Ok(())) = &result {
+            self.position += (buf.filled().len() - before) as u64;
+        }
+        result
+    }
+}
+
+impl AsyncWrite for TokioFileStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        loop {
+            match self.mode.take().expect("mode is always populated") {
+                TokioFileStreamMode::Read(reader) => {
+                    // Switching from Read to Write: the BufReader may have
+                    // read ahead of `position`, so the new writer must seek
+                    // back before any bytes are written, mirroring the sync
+                    // `FileStream`'s mode switch.
+                    let mut writer = BufWriter::new(reader.into_inner());
+                    if let Err(err) =
+                        Pin::new(&mut writer).start_seek(io::SeekFrom::Start(self.position))
+                    {
+                        self.mode = Some(TokioFileStreamMode::Write(writer));
+                        return Poll::Ready(Err(err));
+                    }
+                    self.mode = Some(TokioFileStreamMode::Switching(writer));
+                }
+                TokioFileStreamMode::Switching(mut writer) => {
+                    match Pin::new(&mut writer).poll_complete(cx) {
+                        Poll::Ready(Ok(_)) => {
+                            self.mode = Some(TokioFileStreamMode::Write(writer));
+                        }
+                        Poll::Ready(Err(err)) => {
+                            self.mode = Some(TokioFileStreamMode::Write(writer));
+                            return Poll::Ready(Err(err));
+                        }
+                        Poll::Pending => {
+                            self.mode = Some(TokioFileStreamMode::Switching(writer));
+                            return Poll::Pending;
+                        }
+                    }
+                }
+                TokioFileStreamMode::Write(mut writer) => {
+                    let result = Pin::new(&mut writer).poll_write(cx, buf);
+                    self.mode = Some(TokioFileStreamMode::Write(writer));
+                    if let Poll::Ready(Ok(size)) = &result {
+                        self.position += *size as u64;
+                        self.len += *size as u64;
+                    }
+                    return result;
+                }
+            }
+        }
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.mode_mut() {
+            TokioFileStreamMode::Write(writer) | TokioFileStreamMode::Switching(writer) => {
+                Pin::new(writer).poll_flush(cx)
+            }
+            TokioFileStreamMode::Read(_) => Poll::Ready(Ok(())),
+        }
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match self.mode_mut() {
+            TokioFileStreamMode::Write(writer) | TokioFileStreamMode::Switching(writer) => {
+                Pin::new(writer).poll_shutdown(cx)
+            }
+            TokioFileStreamMode::Read(_) => Poll::Ready(Ok(())),
+        }
+    }
+}
+
+impl AsyncReadStream for TokioFileStream {}
+impl AsyncWriteStream for TokioFileStream {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+    use tempfile::tempdir;
+
+    #[tokio::test]
+    async fn write_read_round_trip() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("async_write_read");
+
+        let mut stream = TokioFileStream::open(&path).await.unwrap();
+        let chunks = vec![Ok(Bytes::from_static(b"hello ")), Ok(Bytes::from_static(b"world"))];
+        stream.write_from_stream(stream::iter(chunks)).await.unwrap();
+
+        AsyncSeekStream::seek(&mut stream, 0).await.unwrap();
+        let mut buffer = Vec::new();
+        stream.read_to_end(&mut buffer).await.unwrap();
+
+        assert_eq!(b"hello world".to_vec(), buffer);
+    }
+}
@@ -0,0 +1,279 @@
+//! Typed codec layer that reads and writes primitives on top of any stream.
+use crate::{ReadStream, Result, WriteStream};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+
+/// Byte order used when encoding multi-byte values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+impl Default for Endianness {
+    fn default() -> Self {
+        Self::Little
+    }
+}
+
+/// Number of bytes used to prefix a string with its length.
+///
+/// Fixed at `u32` so encoded files are portable across 32 and 64-bit
+/// platforms regardless of the host `usize` width.
+pub const STRING_LENGTH_BYTES: usize = 4;
+
+// `read_string`/`write_string` encode the length prefix via `read_u32`/
+// `write_u32` directly; this ties that hard-coded width back to the
+// constant above so the two can't silently drift apart.
+const _: () = assert!(STRING_LENGTH_BYTES == core::mem::size_of::<u32>());
+
+/// Reads primitives out of an underlying [`ReadStream`].
+pub struct BinaryReader<R: ReadStream> {
+    stream: R,
+    endian: Endianness,
+}
+
+impl<R: ReadStream> BinaryReader<R> {
+    /// Create a binary reader over `stream` using `endian` byte order.
+    pub fn new(stream: R, endian: Endianness) -> Self {
+        Self { stream, endian }
+    }
+
+    /// Consume the reader, returning the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.stream
+    }
+
+    /// Mutable access to the underlying stream, e.g. to seek.
+    pub fn stream_mut(&mut self) -> &mut R {
+        &mut self.stream
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buffer = [0u8; N];
+        self.stream.read_exact(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_i8(&mut self) -> Result<i8> {
+        Ok(self.read_u8()? as i8)
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16> {
+        let buffer = self.read_array::<2>()?;
+        Ok(match self.endian {
+            Endianness::Big => u16::from_be_bytes(buffer),
+            Endianness::Little => u16::from_le_bytes(buffer),
+        })
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16> {
+        Ok(self.read_u16()? as i16)
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32> {
+        let buffer = self.read_array::<4>()?;
+        Ok(match self.endian {
+            Endianness::Big => u32::from_be_bytes(buffer),
+            Endianness::Little => u32::from_le_bytes(buffer),
+        })
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32> {
+        Ok(self.read_u32()? as i32)
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64> {
+        let buffer = self.read_array::<8>()?;
+        Ok(match self.endian {
+            Endianness::Big => u64::from_be_bytes(buffer),
+            Endianness::Little => u64::from_le_bytes(buffer),
+        })
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64> {
+        Ok(self.read_u64()? as i64)
+    }
+
+    pub fn read_f32(&mut self) -> Result<f32> {
+        Ok(f32::from_bits(self.read_u32()?))
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64> {
+        Ok(f64::from_bits(self.read_u64()?))
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool> {
+        Ok(self.read_u8()? != 0)
+    }
+
+    pub fn read_char(&mut self) -> Result<char> {
+        let scalar = self.read_u32()?;
+        char::from_u32(scalar).ok_or(crate::error::StreamError::InvalidChar(scalar))
+    }
+
+    pub fn read_string(&mut self) -> Result<String> {
+        let len = self.read_u32()? as usize;
+        let mut buffer = Vec::new();
+        buffer.resize(len, 0u8);
+        self.stream.read_exact(&mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+/// Writes primitives into an underlying [`WriteStream`].
+pub struct BinaryWriter<W: WriteStream> {
+    stream: W,
+    endian: Endianness,
+}
+
+impl<W: WriteStream> BinaryWriter<W> {
+    /// Create a binary writer over `stream` using `endian` byte order.
+    pub fn new(stream: W, endian: Endianness) -> Self {
+        Self { stream, endian }
+    }
+
+    /// Consume the writer, returning the underlying stream.
+    pub fn into_inner(self) -> W {
+        self.stream
+    }
+
+    /// Mutable access to the underlying stream, e.g. to seek.
+    pub fn stream_mut(&mut self) -> &mut W {
+        &mut self.stream
+    }
+
+    pub fn write_u8(&mut self, value: u8) -> Result<()> {
+        self.stream.write_all(&[value])?;
+        Ok(())
+    }
+
+    pub fn write_i8(&mut self, value: i8) -> Result<()> {
+        self.write_u8(value as u8)
+    }
+
+    pub fn write_u16(&mut self, value: u16) -> Result<()> {
+        let bytes = match self.endian {
+            Endianness::Big => value.to_be_bytes(),
+            Endianness::Little => value.to_le_bytes(),
+        };
+        self.stream.write_all(&bytes)?;
+        Ok(())
+    }
+
+    pub fn write_i16(&mut self, value: i16) -> Result<()> {
+        self.write_u16(value as u16)
+    }
+
+    pub fn write_u32(&mut self, value: u32) -> Result<()> {
+        let bytes = match self.endian {
+            Endianness::Big => value.to_be_bytes(),
+            Endianness::Little => value.to_le_bytes(),
+        };
+        self.stream.write_all(&bytes)?;
+        Ok(())
+    }
+
+    pub fn write_i32(&mut self, value: i32) -> Result<()> {
+        self.write_u32(value as u32)
+    }
+
+    pub fn write_u64(&mut self, value: u64) -> Result<()> {
+        let bytes = match self.endian {
+            Endianness::Big => value.to_be_bytes(),
+            Endianness::Little => value.to_le_bytes(),
+        };
+        self.stream.write_all(&bytes)?;
+        Ok(())
+    }
+
+    pub fn write_i64(&mut self, value: i64) -> Result<()> {
+        self.write_u64(value as u64)
+    }
+
+    pub fn write_f32(&mut self, value: f32) -> Result<()> {
+        self.write_u32(value.to_bits())
+    }
+
+    pub fn write_f64(&mut self, value: f64) -> Result<()> {
+        self.write_u64(value.to_bits())
+    }
+
+    pub fn write_bool(&mut self, value: bool) -> Result<()> {
+        self.write_u8(value as u8)
+    }
+
+    pub fn write_char(&mut self, value: char) -> Result<()> {
+        self.write_u32(value as u32)
+    }
+
+    pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> Result<()> {
+        let bytes = value.as_ref().as_bytes();
+        self.write_u32(bytes.len().try_into()?)?;
+        self.stream.write_all(bytes)?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{BinaryReader, BinaryWriter, Endianness};
+    use crate::memory::MemoryStream;
+
+    #[test]
+    fn round_trip_little_endian() {
+        let mut writer = BinaryWriter::new(MemoryStream::new(), Endianness::Little);
+        writer.write_u8(1).unwrap();
+        writer.write_u16(2).unwrap();
+        writer.write_u32(3).unwrap();
+        writer.write_u64(4).unwrap();
+        writer.write_f32(1.5).unwrap();
+        writer.write_f64(2.5).unwrap();
+        writer.write_bool(true).unwrap();
+        writer.write_char('x').unwrap();
+        writer.write_string("hello").unwrap();
+
+        let mut stream = writer.into_inner();
+        crate::SeekStream::seek(&mut stream, 0).unwrap();
+        let mut reader = BinaryReader::new(stream, Endianness::Little);
+
+        assert_eq!(1, reader.read_u8().unwrap());
+        assert_eq!(2, reader.read_u16().unwrap());
+        assert_eq!(3, reader.read_u32().unwrap());
+        assert_eq!(4, reader.read_u64().unwrap());
+        assert_eq!(1.5, reader.read_f32().unwrap());
+        assert_eq!(2.5, reader.read_f64().unwrap());
+        assert!(reader.read_bool().unwrap());
+        assert_eq!('x', reader.read_char().unwrap());
+        assert_eq!("hello", reader.read_string().unwrap());
+    }
+
+    #[test]
+    fn round_trip_big_endian() {
+        let mut writer = BinaryWriter::new(MemoryStream::new(), Endianness::Big);
+        writer.write_u32(0xdead_beef).unwrap();
+
+        let mut stream = writer.into_inner();
+        crate::SeekStream::seek(&mut stream, 0).unwrap();
+        let mut reader = BinaryReader::new(stream, Endianness::Big);
+
+        assert_eq!(0xdead_beef, reader.read_u32().unwrap());
+    }
+
+    #[test]
+    fn invalid_char_is_an_error() {
+        let mut writer = BinaryWriter::new(MemoryStream::new(), Endianness::Little);
+        writer.write_u32(0xd800).unwrap();
+
+        let mut stream = writer.into_inner();
+        crate::SeekStream::seek(&mut stream, 0).unwrap();
+        let mut reader = BinaryReader::new(stream, Endianness::Little);
+
+        assert!(reader.read_char().is_err());
+    }
+}
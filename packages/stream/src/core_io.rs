@@ -0,0 +1,62 @@
+//! Minimal backend-agnostic I/O traits used when the `std` feature is off.
+//!
+//! These mirror the pieces of `std::io::{Read, Write}` that the stream
+//! traits need, so the same `BinaryReader`/`BinaryWriter` and stream code
+//! can drive a bare-metal block device with only `core`/`alloc` available.
+use core::fmt;
+
+/// Kind of failure reported by a [`CoreIoError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoreIoErrorKind {
+    UnexpectedEof,
+    WriteZero,
+    Other,
+}
+
+/// Backend-agnostic I/O error for `no_std` builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoreIoError(pub CoreIoErrorKind);
+
+impl fmt::Display for CoreIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            CoreIoErrorKind::UnexpectedEof => write!(f, "unexpected end of stream"),
+            CoreIoErrorKind::WriteZero => write!(f, "write target accepted no bytes"),
+            CoreIoErrorKind::Other => write!(f, "I/O error"),
+        }
+    }
+}
+
+/// `core`-only counterpart of `std::io::Read`.
+pub trait Read {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, CoreIoError>;
+
+    fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<(), CoreIoError> {
+        while !buf.is_empty() {
+            match self.read(buf)? {
+                0 => return Err(CoreIoError(CoreIoErrorKind::UnexpectedEof)),
+                n => buf = &mut buf[n..],
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `core`-only counterpart of `std::io::Write`.
+pub trait Write {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, CoreIoError>;
+
+    fn flush(&mut self) -> Result<(), CoreIoError> {
+        Ok(())
+    }
+
+    fn write_all(&mut self, mut buf: &[u8]) -> Result<(), CoreIoError> {
+        while !buf.is_empty() {
+            match self.write(buf)? {
+                0 => return Err(CoreIoError(CoreIoErrorKind::WriteZero)),
+                n => buf = &buf[n..],
+            }
+        }
+        Ok(())
+    }
+}
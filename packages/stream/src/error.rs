@@ -1,5 +1,7 @@
+#[cfg(feature = "std")]
 use thiserror::Error;
 
+#[cfg(feature = "std")]
 #[derive(Error, Debug)]
 pub enum StreamError {
     #[error("Try to read after end of file")]
@@ -7,5 +9,59 @@ pub enum StreamError {
     #[error(transparent)]
     Io(#[from] std::io::Error),
     #[error(transparent)]
-    TryFromInt(#[from] std::num::TryFromIntError),
+    TryFromInt(#[from] core::num::TryFromIntError),
+    #[error("invalid utf-8 in string data")]
+    Utf8(#[from] std::string::FromUtf8Error),
+    #[error("invalid char scalar value: {0:#x}")]
+    InvalidChar(u32),
+}
+
+/// `no_std` builds have no `std::io::Error` and can't derive via `thiserror`
+/// (no `std` to implement `std::error::Error` against), so the variants are
+/// spelled out by hand against `core`/`alloc` types instead.
+#[cfg(not(feature = "std"))]
+#[derive(Debug)]
+pub enum StreamError {
+    UnexpectedEof,
+    Io(crate::core_io::CoreIoError),
+    TryFromInt(core::num::TryFromIntError),
+    Utf8(alloc::string::FromUtf8Error),
+    InvalidChar(u32),
+}
+
+#[cfg(not(feature = "std"))]
+impl core::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StreamError::UnexpectedEof => write!(f, "try to read after end of file"),
+            StreamError::Io(e) => write!(f, "{e}"),
+            StreamError::TryFromInt(e) => write!(f, "{e}"),
+            StreamError::Utf8(e) => write!(f, "invalid utf-8 in string data: {e}"),
+            StreamError::InvalidChar(c) => write!(f, "invalid char scalar value: {c:#x}"),
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl core::error::Error for StreamError {}
+
+#[cfg(not(feature = "std"))]
+impl From<crate::core_io::CoreIoError> for StreamError {
+    fn from(err: crate::core_io::CoreIoError) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<core::num::TryFromIntError> for StreamError {
+    fn from(err: core::num::TryFromIntError) -> Self {
+        StreamError::TryFromInt(err)
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl From<alloc::string::FromUtf8Error> for StreamError {
+    fn from(err: alloc::string::FromUtf8Error) -> Self {
+        StreamError::Utf8(err)
+    }
 }
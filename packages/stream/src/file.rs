@@ -1,17 +1,26 @@
 //! Stream for operating on files.
 use crate::{ReadStream, Result, SeekStream, Stream, WriteStream};
-use std::fs::{File, Metadata, OpenOptions};
+use std::fs::{File, OpenOptions};
 use std::io::{BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write};
 use std::path::Path;
 use std::sync::Arc;
 
+/// How [`FileStream::open`] should open the underlying file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenType {
+    /// Open an existing file for reading only.
+    Open,
+    /// Open for reading and writing, creating the file if it does not exist.
+    OpenAndCreate,
+    /// Create the file for writing, truncating it if it already exists.
+    Truncate,
+}
+
 /// Stream that wraps a file.
 pub struct FileStream {
     position: u64,
-    metadata: Metadata,
     file: Arc<File>,
     mode: FileStreamMode,
-    len: u64,
 }
 
 pub enum FileStreamMode {
@@ -20,55 +29,71 @@ pub enum FileStreamMode {
 }
 
 impl FileStream {
-    pub fn new_read<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let file = OpenOptions::new()
-            .create(true)
-            .truncate(false)
-            .read(true)
-            .write(true)
-            .open(path)?;
+    /// Open `path` according to `open_type`.
+    pub fn open<P: AsRef<Path>>(path: P, open_type: OpenType) -> Result<Self> {
+        let (file, start_writing) = match open_type {
+            OpenType::Open => (OpenOptions::new().read(true).open(path)?, false),
+            OpenType::OpenAndCreate => (
+                OpenOptions::new()
+                    .create(true)
+                    .truncate(false)
+                    .read(true)
+                    .write(true)
+                    .open(path)?,
+                false,
+            ),
+            OpenType::Truncate => (
+                OpenOptions::new()
+                    .create(true)
+                    .truncate(true)
+                    .read(true)
+                    .write(true)
+                    .open(path)?,
+                true,
+            ),
+        };
+
         let file = Arc::new(file);
-        let meta = file.metadata()?;
+        let mode = if start_writing {
+            FileStreamMode::Write(BufWriter::new(file.clone()))
+        } else {
+            FileStreamMode::Read(BufReader::new(file.clone()))
+        };
+
         Ok(Self {
-            len: meta.len(),
-            metadata: meta,
             position: 0,
-            mode: FileStreamMode::Read(BufReader::new(file.clone())),
             file,
+            mode,
         })
     }
 
-    pub fn new_write<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let file = OpenOptions::new()
-            .create(true)
-            .truncate(false)
-            .read(true)
-            .write(true)
-            .open(path)?;
-        let file = Arc::new(file);
-        let meta = file.metadata()?;
-        Ok(Self {
-            len: meta.len(),
-            metadata: file.metadata()?,
-            position: 0,
-            mode: FileStreamMode::Write(BufWriter::new(file.clone())),
-            file,
-        })
+    /// Access the underlying file, e.g. for positional reads/writes.
+    ///
+    /// Flushes a pending `BufWriter` first, so callers that bypass the
+    /// buffered `Read`/`Write` impls (e.g. positional access via syscalls on
+    /// the raw fd) still see just-written, unflushed bytes.
+    pub(crate) fn file(&mut self) -> Result<&File> {
+        if let FileStreamMode::Write(writer) = &mut self.mode {
+            writer.flush()?;
+        }
+        Ok(&self.file)
+    }
+
+    /// Current length of the file, queried live rather than cached.
+    pub fn size(&mut self) -> Result<u64> {
+        Ok(self.file()?.metadata()?.len())
     }
 }
 
 impl SeekStream for FileStream {
     fn seek(&mut self, to: u64) -> Result<u64> {
         let result = match &mut self.mode {
-            FileStreamMode::Read(reader) => Ok(reader.seek(SeekFrom::Start(to))?),
-            FileStreamMode::Write(writer) => Ok(writer.seek(SeekFrom::Start(to))?),
-        };
+            FileStreamMode::Read(reader) => reader.seek(SeekFrom::Start(to)),
+            FileStreamMode::Write(writer) => writer.seek(SeekFrom::Start(to)),
+        }?;
 
-        if result.is_ok() {
-            self.position = to;
-        }
-
-        result
+        self.position = to;
+        Ok(result)
     }
 
     fn position(&mut self) -> Result<u64> {
@@ -76,7 +101,7 @@ impl SeekStream for FileStream {
     }
 
     fn len(&mut self) -> Result<u64> {
-        Ok(self.len)
+        self.size()
     }
 }
 
@@ -93,6 +118,10 @@ impl Read for FileStream {
         }
 
         if let FileStreamMode::Read(reader) = &mut self.mode {
+            // A short read here (including 0 bytes at end-of-file) is a
+            // valid `Read` result, not an error; callers that need exactly
+            // `buffer.len()` bytes should use `read_exact`, which already
+            // surfaces end-of-file as `ErrorKind::UnexpectedEof`.
             let size = reader.read(buffer)?;
             self.position += size as u64;
             return Ok(size);
@@ -105,12 +134,13 @@ impl Read for FileStream {
 impl Write for FileStream {
     fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
         if let FileStreamMode::Read(_) = &mut self.mode {
-            self.mode = FileStreamMode::Write(BufWriter::new(self.file.clone()));
+            let mut writer = BufWriter::new(self.file.clone());
+            writer.seek(SeekFrom::Start(self.position))?;
+            self.mode = FileStreamMode::Write(writer);
         }
 
         if let FileStreamMode::Write(writer) = &mut self.mode {
             let size = writer.write(bytes)?;
-            self.len += size as u64;
             self.position += size as u64;
             return Ok(size);
         }
@@ -136,14 +166,19 @@ impl Stream for FileStream {}
 mod tests {
     use std::io::{Read, Write};
 
-    use tempfile::{tempdir, tempfile};
+    use tempfile::tempdir;
 
-    use crate::{file::FileStream, SeekStream};
+    use crate::{
+        file::{FileStream, OpenType},
+        SeekStream,
+    };
 
     #[test]
     pub fn write() {
         let temp_dir = tempdir().unwrap();
-        let mut stream = FileStream::new_write(temp_dir.path().join("write")).expect("file open");
+        let mut stream =
+            FileStream::open(temp_dir.path().join("write"), OpenType::OpenAndCreate)
+                .expect("file open");
 
         let buffer: [u8; 6] = [0, 1, 2, 3, 4, 5];
         stream.write_all(&buffer).expect("all should be written");
@@ -155,8 +190,11 @@ mod tests {
     #[test]
     pub fn write_read() {
         let temp_dir = tempdir().unwrap();
-        let mut stream =
-            FileStream::new_write(temp_dir.path().join("write_read")).expect("file open");
+        let mut stream = FileStream::open(
+            temp_dir.path().join("write_read"),
+            OpenType::OpenAndCreate,
+        )
+        .expect("file open");
 
         let buffer: [u8; 6] = [0, 1, 2, 3, 4, 5];
         stream.write_all(&buffer).expect("all should be written");
@@ -175,8 +213,11 @@ mod tests {
     #[test]
     pub fn read_to_end() {
         let temp_dir = tempdir().unwrap();
-        let mut stream =
-            FileStream::new_write(temp_dir.path().join("read_to_end")).expect("file open");
+        let mut stream = FileStream::open(
+            temp_dir.path().join("read_to_end"),
+            OpenType::OpenAndCreate,
+        )
+        .expect("file open");
 
         let buffer: [u8; 2] = [42, 10];
         stream.write_all(&buffer).expect("all should be written");
@@ -190,4 +231,42 @@ mod tests {
 
         assert_eq!(buffer, buffer_read.as_slice());
     }
+
+    #[test]
+    pub fn read_past_end_is_a_clean_short_read() {
+        let temp_dir = tempdir().unwrap();
+        let mut stream = FileStream::open(
+            temp_dir.path().join("read_past_end"),
+            OpenType::OpenAndCreate,
+        )
+        .expect("file open");
+
+        stream.write_all(&[1, 2, 3]).expect("all should be written");
+        stream.seek(0).unwrap();
+
+        let mut buffer = [0u8; 16];
+        let err = stream.read_exact(&mut buffer).unwrap_err();
+        assert_eq!(std::io::ErrorKind::UnexpectedEof, err.kind());
+    }
+
+    #[test]
+    pub fn open_missing_file_fails() {
+        let temp_dir = tempdir().unwrap();
+        let result = FileStream::open(temp_dir.path().join("missing"), OpenType::Open);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn truncate_clears_existing_contents() {
+        let temp_dir = tempdir().unwrap();
+        let path = temp_dir.path().join("truncate");
+
+        let mut stream =
+            FileStream::open(&path, OpenType::OpenAndCreate).expect("file open");
+        stream.write_all(&[1, 2, 3]).expect("all should be written");
+        drop(stream);
+
+        let mut stream = FileStream::open(&path, OpenType::Truncate).expect("file open");
+        assert_eq!(0, stream.size().unwrap());
+    }
 }
@@ -1,13 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+#[cfg(feature = "async")]
+pub mod asynch;
+pub mod binary;
+#[cfg(not(feature = "std"))]
+pub mod core_io;
 pub mod error;
+#[cfg(feature = "std")]
 pub mod file;
 pub mod memory;
+pub mod positional;
 pub mod slice;
 
+#[cfg(feature = "std")]
 use std::io::{Read, Write};
 
+#[cfg(not(feature = "std"))]
+use crate::core_io::{Read, Write};
+
 use error::StreamError;
 
-pub type Result<T> = std::result::Result<T, StreamError>;
+pub type Result<T> = core::result::Result<T, StreamError>;
 
 /// Trait for streams that can seek.
 pub trait SeekStream {
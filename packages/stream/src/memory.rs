@@ -1,9 +1,9 @@
 //! Stream that reads from and writes to an owned buffer.
 use crate::{ReadStream, Result, SeekStream, Stream, WriteStream};
-use std::{
-    cmp::min,
-    io::{Read, Write},
-};
+use core::cmp::min;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 /// Stream that wraps an owned buffer.
 pub struct MemoryStream {
@@ -27,25 +27,27 @@ impl Default for MemoryStream {
     }
 }
 
-impl SeekStream for MemoryStream {
-    fn seek(&mut self, to: u64) -> Result<u64> {
-        self.position = to.try_into()?;
-        Ok(self.position.try_into()?)
-    }
-
-    fn position(&mut self) -> Result<u64> {
-        Ok(self.position.try_into()?)
+impl MemoryStream {
+    /// Access the underlying buffer, e.g. for positional reads.
+    pub(crate) fn buffer(&self) -> &[u8] {
+        &self.buffer
     }
 
-    fn len(&mut self) -> Result<u64> {
-        Ok(self.buffer.len().try_into()?)
+    /// Write `bytes` at `offset`, growing the buffer if necessary.
+    pub(crate) fn write_at_buffer(&mut self, offset: usize, bytes: &[u8]) -> Result<()> {
+        let end = offset
+            .checked_add(bytes.len())
+            .ok_or(crate::error::StreamError::UnexpectedEof)?;
+        if end > self.buffer.len() {
+            self.buffer.resize(end, 0);
+        }
+        self.buffer[offset..end].copy_from_slice(bytes);
+        Ok(())
     }
-}
 
-impl Read for MemoryStream {
-    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+    fn read_into(&mut self, buffer: &mut [u8]) -> usize {
         if self.position >= self.buffer.len() {
-            return Ok(0);
+            return 0;
         }
 
         let source_position = min(self.position + buffer.len(), self.buffer.len());
@@ -55,22 +57,56 @@ impl Read for MemoryStream {
         let len = source_position - self.position;
         self.position += len;
 
-        Ok(len)
+        len
     }
-}
 
-impl Write for MemoryStream {
-    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+    fn write_from(&mut self, bytes: &[u8]) -> usize {
         let bytes_to_end = self.buffer.len() - self.position;
         if bytes.len() > bytes_to_end {
             let bytes_out_of_buffer = bytes.len() - bytes_to_end;
-            self.buffer.extend(vec![0u8; bytes_out_of_buffer]);
+            self.buffer.resize(self.buffer.len() + bytes_out_of_buffer, 0);
         }
 
         self.buffer[self.position..self.position + bytes.len()].copy_from_slice(bytes);
         self.position += bytes.len();
 
-        Ok(bytes.len())
+        bytes.len()
+    }
+}
+
+impl SeekStream for MemoryStream {
+    fn seek(&mut self, to: u64) -> Result<u64> {
+        self.position = to.try_into()?;
+        Ok(self.position.try_into()?)
+    }
+
+    fn position(&mut self) -> Result<u64> {
+        Ok(self.position.try_into()?)
+    }
+
+    fn len(&mut self) -> Result<u64> {
+        Ok(self.buffer.len().try_into()?)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for MemoryStream {
+    fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.read_into(buffer))
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl crate::core_io::Read for MemoryStream {
+    fn read(&mut self, buffer: &mut [u8]) -> core::result::Result<usize, crate::core_io::CoreIoError> {
+        Ok(self.read_into(buffer))
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for MemoryStream {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        Ok(self.write_from(bytes))
     }
 
     fn flush(&mut self) -> std::io::Result<()> {
@@ -78,6 +114,13 @@ impl Write for MemoryStream {
     }
 }
 
+#[cfg(not(feature = "std"))]
+impl crate::core_io::Write for MemoryStream {
+    fn write(&mut self, bytes: &[u8]) -> core::result::Result<usize, crate::core_io::CoreIoError> {
+        Ok(self.write_from(bytes))
+    }
+}
+
 impl From<Vec<u8>> for MemoryStream {
     fn from(buffer: Vec<u8>) -> Self {
         MemoryStream {
@@ -97,7 +140,7 @@ impl ReadStream for MemoryStream {}
 impl WriteStream for MemoryStream {}
 impl Stream for MemoryStream {}
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::io::{Read, Write};
 
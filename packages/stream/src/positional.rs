@@ -0,0 +1,218 @@
+//! Positional (pread/pwrite-style) access that leaves the cursor untouched.
+use crate::Result;
+
+/// Trait for streams that support reading at an offset without disturbing
+/// the stream's current [`position`](crate::SeekStream::position).
+pub trait PositionalRead {
+    /// Read into `buf` starting at `offset`, leaving the cursor unchanged.
+    fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<()>;
+}
+
+/// Trait for streams that support writing at an offset without disturbing
+/// the stream's current [`position`](crate::SeekStream::position).
+pub trait PositionalWrite {
+    /// Write `buf` starting at `offset`, leaving the cursor unchanged.
+    fn write_at(&mut self, offset: u64, buf: &[u8]) -> Result<()>;
+}
+
+#[cfg(all(unix, feature = "std"))]
+mod file_impl {
+    use super::{PositionalRead, PositionalWrite};
+    use crate::file::FileStream;
+    use crate::Result;
+    use std::os::unix::fs::FileExt;
+
+    impl PositionalRead for FileStream {
+        fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+            Ok(self.file()?.read_exact_at(buf, offset)?)
+        }
+    }
+
+    impl PositionalWrite for FileStream {
+        fn write_at(&mut self, offset: u64, buf: &[u8]) -> Result<()> {
+            Ok(self.file()?.write_all_at(buf, offset)?)
+        }
+    }
+}
+
+#[cfg(all(windows, feature = "std"))]
+mod file_impl {
+    use super::{PositionalRead, PositionalWrite};
+    use crate::file::FileStream;
+    use crate::Result;
+    use std::io::{Error, ErrorKind};
+    use std::os::windows::fs::FileExt;
+
+    impl PositionalRead for FileStream {
+        fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+            let mut read = 0;
+            while read < buf.len() {
+                let n = self.file()?.seek_read(&mut buf[read..], offset + read as u64)?;
+                if n == 0 {
+                    return Err(Error::from(ErrorKind::UnexpectedEof).into());
+                }
+                read += n;
+            }
+            Ok(())
+        }
+    }
+
+    impl PositionalWrite for FileStream {
+        fn write_at(&mut self, offset: u64, buf: &[u8]) -> Result<()> {
+            let mut written = 0;
+            while written < buf.len() {
+                let n = self.file()?.seek_write(&buf[written..], offset + written as u64)?;
+                if n == 0 {
+                    return Err(Error::from(ErrorKind::WriteZero).into());
+                }
+                written += n;
+            }
+            Ok(())
+        }
+    }
+}
+
+mod memory_impl {
+    use super::{PositionalRead, PositionalWrite};
+    use crate::error::StreamError;
+    use crate::memory::MemoryStream;
+    use crate::Result;
+
+    impl PositionalRead for MemoryStream {
+        fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+            let offset: usize = offset.try_into()?;
+            let buffer = self.buffer();
+            let end = offset
+                .checked_add(buf.len())
+                .filter(|&end| end <= buffer.len())
+                .ok_or(StreamError::UnexpectedEof)?;
+
+            buf.copy_from_slice(&buffer[offset..end]);
+            Ok(())
+        }
+    }
+
+    impl PositionalWrite for MemoryStream {
+        fn write_at(&mut self, offset: u64, buf: &[u8]) -> Result<()> {
+            let offset: usize = offset.try_into()?;
+            self.write_at_buffer(offset, buf)
+        }
+    }
+}
+
+mod slice_impl {
+    use super::PositionalRead;
+    use crate::error::StreamError;
+    use crate::slice::SliceStream;
+    use crate::Result;
+
+    impl PositionalRead for SliceStream<'_> {
+        fn read_at(&mut self, offset: u64, buf: &mut [u8]) -> Result<()> {
+            let offset: usize = offset.try_into()?;
+            let buffer = self.buffer();
+            let end = offset
+                .checked_add(buf.len())
+                .filter(|&end| end <= buffer.len())
+                .ok_or(StreamError::UnexpectedEof)?;
+
+            buf.copy_from_slice(&buffer[offset..end]);
+            Ok(())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{PositionalRead, PositionalWrite};
+    use crate::memory::MemoryStream;
+    use crate::slice::SliceStream;
+    use crate::SeekStream;
+    use std::io::Write;
+
+    #[test]
+    fn memory_positional_leaves_cursor() {
+        let mut stream = MemoryStream::new();
+        stream.write_all(&[0, 1, 2, 3, 4, 5]).unwrap();
+        stream.seek(2).unwrap();
+
+        let mut buf = [0u8; 2];
+        stream.read_at(0, &mut buf).unwrap();
+        assert_eq!([0, 1], buf);
+        assert_eq!(2, stream.position().unwrap());
+
+        stream.write_at(0, &[9, 9]).unwrap();
+        assert_eq!(2, stream.position().unwrap());
+
+        let mut readback = [0u8; 2];
+        stream.read_at(0, &mut readback).unwrap();
+        assert_eq!([9, 9], readback);
+    }
+
+    #[test]
+    fn slice_positional_leaves_cursor() {
+        let data = [0u8, 1, 2, 3, 4, 5];
+        let mut stream = SliceStream::new(&data);
+        stream.seek(3).unwrap();
+
+        let mut buf = [0u8; 3];
+        stream.read_at(0, &mut buf).unwrap();
+        assert_eq!([0, 1, 2], buf);
+        assert_eq!(3, stream.position().unwrap());
+    }
+
+    #[test]
+    fn memory_read_at_past_end_errors() {
+        let mut stream = MemoryStream::new();
+        stream.write_all(&[1, 2, 3]).unwrap();
+
+        let mut buf = [0u8; 8];
+        assert!(stream.read_at(0, &mut buf).is_err());
+    }
+
+    #[test]
+    fn slice_read_at_past_end_errors() {
+        let data = [1u8, 2, 3];
+        let mut stream = SliceStream::new(&data);
+
+        let mut buf = [0u8; 8];
+        assert!(stream.read_at(0, &mut buf).is_err());
+    }
+
+    #[test]
+    fn memory_write_at_overflowing_offset_errors() {
+        let mut stream = MemoryStream::new();
+        assert!(stream.write_at(u64::MAX - 2, &[1, 2, 3]).is_err());
+    }
+
+    #[cfg(any(unix, windows))]
+    #[test]
+    fn file_positional_sees_buffered_write() {
+        use crate::file::{FileStream, OpenType};
+        use std::io::Read;
+        use tempfile::tempdir;
+
+        let temp_dir = tempdir().unwrap();
+        let mut stream =
+            FileStream::open(temp_dir.path().join("positional"), OpenType::OpenAndCreate)
+                .expect("file open");
+
+        // Goes through the buffered `Write` impl, so the bytes sit in the
+        // `BufWriter` and haven't reached the fd yet.
+        stream.write_all(&[1, 2, 3, 4]).expect("all should be written");
+
+        // A positional read over the same region must observe the buffered
+        // bytes, not stale (empty) on-disk content.
+        let mut buf = [0u8; 4];
+        stream.read_at(0, &mut buf).unwrap();
+        assert_eq!([1, 2, 3, 4], buf);
+
+        // A positional write must land after the buffered write is flushed,
+        // not be clobbered by a later implicit flush.
+        stream.write_at(2, &[9, 9]).unwrap();
+        stream.seek(0).unwrap();
+
+        let mut readback = [0u8; 4];
+        stream.read_exact(&mut readback).unwrap();
+        assert_eq!([1, 2, 9, 9], readback);
+    }
+}
@@ -1,9 +1,6 @@
 //! Stream that reads from a slice of bytes.
 use crate::{ReadStream, Result, SeekStream};
-use std::{
-    cmp::min,
-    io::{Error, ErrorKind, Read},
-};
+use core::cmp::min;
 
 /// Stream that wraps a slice of bytes.
 pub struct SliceStream<'a> {
@@ -21,6 +18,28 @@ impl<'a> SliceStream<'a> {
     }
 }
 
+impl SliceStream<'_> {
+    /// Access the underlying buffer, e.g. for positional reads.
+    pub(crate) fn buffer(&self) -> &[u8] {
+        self.buffer
+    }
+
+    fn read_into(&mut self, buffer: &mut [u8]) -> usize {
+        if self.position >= self.buffer.len() {
+            return 0;
+        }
+
+        let source_position = min(self.position + buffer.len(), self.buffer.len());
+        (buffer[..(source_position - self.position)])
+            .copy_from_slice(&self.buffer[self.position..source_position]);
+
+        let len = source_position - self.position;
+        self.position += len;
+
+        len
+    }
+}
+
 impl SeekStream for SliceStream<'_> {
     fn seek(&mut self, to: u64) -> Result<u64> {
         self.position = to.try_into()?;
@@ -36,26 +55,23 @@ impl SeekStream for SliceStream<'_> {
     }
 }
 
-impl Read for SliceStream<'_> {
+#[cfg(feature = "std")]
+impl std::io::Read for SliceStream<'_> {
     fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
-        if self.position >= self.buffer.len() {
-            return Ok(0);
-        }
-
-        let source_position = min(self.position + buffer.len(), self.buffer.len());
-        (buffer[..(source_position - self.position)])
-            .copy_from_slice(&self.buffer[self.position..source_position]);
-
-        let len = source_position - self.position;
-        self.position += len;
+        Ok(self.read_into(buffer))
+    }
+}
 
-        Ok(len)
+#[cfg(not(feature = "std"))]
+impl crate::core_io::Read for SliceStream<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> core::result::Result<usize, crate::core_io::CoreIoError> {
+        Ok(self.read_into(buffer))
     }
 }
 
 impl ReadStream for SliceStream<'_> {}
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::io::Read;
 